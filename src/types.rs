@@ -1,8 +1,128 @@
-use serde::Serialize;
+//! Shared DTOs serialized across the wire between the Rust backend and the
+//! JS/TS frontend.
+//!
+//! Convention: every DTO in this module uses `#[serde(rename_all = "camelCase")]`
+//! so the JSON it emits matches idiomatic JavaScript field naming and lines up
+//! with the TypeScript interfaces generated from it.
 
-#[derive(Serialize)]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Person {
     pub name: String,
     pub age: u32,
+    #[serde(default)]
     pub favourite_food: Option<String>
+}
+
+impl Person {
+    /// Converts this wire DTO into the persisted domain entity, assigning it
+    /// a fresh stable identifier.
+    pub fn into_record(self) -> PersonRecord {
+        PersonRecord {
+            id: Uuid::new_v4(),
+            name: self.name,
+            age: self.age,
+            favourite_food: self.favourite_food,
+        }
+    }
+
+    /// Validates incoming data before it is accepted, returning every
+    /// field-level violation rather than bailing out on the first one so the
+    /// frontend can highlight all offending inputs at once.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        if self.name.trim().is_empty() {
+            errors.push(ValidationError {
+                field: "name".to_string(),
+                message: "name must not be empty".to_string(),
+            });
+        }
+
+        if self.age > 150 {
+            errors.push(ValidationError {
+                field: "age".to_string(),
+                message: "age must be 150 or less".to_string(),
+            });
+        }
+
+        if let Some(favourite_food) = &self.favourite_food {
+            if favourite_food.chars().count() > 64 {
+                errors.push(ValidationError {
+                    field: "favouriteFood".to_string(),
+                    message: "favourite_food must be 64 characters or fewer".to_string(),
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// A single field-level validation failure, returned through the
+/// [`ApiResponse`] envelope so the frontend can highlight the offending
+/// input instead of surfacing one opaque error message.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationError {
+    pub field: String,
+    pub message: String,
+}
+
+/// The internally-stored entity backing a [`Person`], carrying a stable
+/// identifier that is never part of the externally-facing JSON contract.
+pub struct PersonRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub age: u32,
+    pub favourite_food: Option<String>,
+}
+
+impl PersonRecord {
+    /// Converts this persisted entity back into the wire DTO, dropping the
+    /// internal identifier.
+    pub fn to_dto(&self) -> Person {
+        Person {
+            name: self.name.clone(),
+            age: self.age,
+            favourite_food: self.favourite_food.clone(),
+        }
+    }
+}
+
+/// A uniform envelope for API responses, so the frontend can distinguish a
+/// successful payload from an error without inspecting the HTTP status alone.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiResponse<T: Serialize> {
+    pub status: i32,
+    pub error: Option<String>,
+    pub data: Option<T>,
+}
+
+impl<T: Serialize> ApiResponse<T> {
+    /// Builds a successful response wrapping `data` with a `200` status.
+    pub fn ok(data: T) -> Self {
+        Self {
+            status: 200,
+            error: None,
+            data: Some(data),
+        }
+    }
+
+    /// Builds an error response carrying a `status` code and message, with no payload.
+    pub fn err(status: i32, msg: impl Into<String>) -> Self {
+        Self {
+            status,
+            error: Some(msg.into()),
+            data: None,
+        }
+    }
 }
\ No newline at end of file